@@ -1,131 +1,157 @@
-// use crate::optional::Optional;
-// use diesel::{row::NamedRow, AppearsOnTable, Expression};
-
-// use diesel::expression::AsExpression;
-// use diesel::{
-//     backend::Backend,
-//     deserialize::{self, FromSql, Queryable, QueryableByName},
-//     result::UnexpectedNullError,
-// };
-// use diesel::Bound;
-// use diesel::expression::*;
-// use diesel::query_builder::QueryId;
-// use diesel::serialize::{self, IsNull, Output, ToSql};
-// use diesel::sql_types::{is_nullable, HasSqlType, Nullable, SingleValue, SqlType};
-// use diesel::NullableExpressionMethods;
-
-// impl<T, DB> HasSqlType<Nullable<T>> for DB
-// where
-//     DB: Backend + HasSqlType<T>,
-//     T: SqlType,
-// {
-//     fn metadata(lookup: &mut DB::MetadataLookup) -> DB::TypeMetadata {
-//         <DB as HasSqlType<T>>::metadata(lookup)
-//     }
-// }
-
-// impl<T> QueryId for Nullable<T>
-// where
-//     T: QueryId + SqlType<IsNull = is_nullable::NotNull>,
-// {
-//     type QueryId = T::QueryId;
-
-//     const HAS_STATIC_QUERY_ID: bool = T::HAS_STATIC_QUERY_ID;
-// }
-
-// impl<T, ST, DB> FromSql<Nullable<ST>, DB> for Optional<T>
-// where
-//     T: FromSql<ST, DB>,
-//     DB: Backend,
-//     ST: SqlType<IsNull = is_nullable::NotNull>,
-// {
-//     fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
-//         T::from_sql(bytes).map(Optional::Def)
-//     }
-
-//     fn from_nullable_sql(bytes: Option<DB::RawValue<'_>>) -> deserialize::Result<Self> {
-//         match bytes {
-//             Some(bytes) => T::from_sql(bytes).map(Optional::Def),
-//             None => Ok(Optional::Null),
-//         }
-//     }
-// }
-
-// impl<T, ST, DB> ToSql<Nullable<ST>, DB> for Option<T>
-// where
-//     T: ToSql<ST, DB>,
-//     DB: Backend,
-//     ST: SqlType<IsNull = is_nullable::NotNull>,
-// {
-//     fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
-//         if let Optional::Def(ref value) = *self {
-//             value.to_sql(out)
-//         } else {
-//             Ok(IsNull::Yes)
-//         }
-//     }
-// }
-
-// impl<T, ST> AsExpression<Nullable<ST>> for Optional<T>
-// where
-//     ST: SqlType<IsNull = is_nullable::NotNull>,
-//     Nullable<ST>: TypedExpressionType,
-// {
-//     type Expression = Bound<Nullable<ST>, Self>;
-
-//     fn as_expression(self) -> Self::Expression {
-//         Bound::new(self)
-//     }
-// }
-
-// impl<'a, T, ST> AsExpression<Nullable<ST>> for &'a Option<T>
-// where
-//     ST: SqlType<IsNull = is_nullable::NotNull>,
-//     Nullable<ST>: TypedExpressionType,
-// {
-//     type Expression = Bound<Nullable<ST>, Self>;
-
-//     fn as_expression(self) -> Self::Expression {
-//         Bound::new(self)
-//     }
-// }
-
-// impl<T, DB> QueryableByName<DB> for Optional<T>
-// where
-//     DB: Backend,
-//     T: QueryableByName<DB>,
-// {
-//     fn build<'a>(row: &impl NamedRow<'a, DB>) -> deserialize::Result<Self> {
-//         match T::build(row) {
-//             Ok(v) => Ok(Optional::Def(v)),
-//             Err(e) if e.is::<UnexpectedNullError>() => Ok(Optional::Null),
-//             Err(e) => Err(e),
-//         }
-//     }
-// }
-
-// impl<ST, T, DB> Queryable<ST, DB> for Option<T>
-// where
-//     ST: SingleValue<IsNull = is_nullable::IsNullable>,
-//     DB: Backend,
-//     Self: FromSql<ST, DB>,
-// {
-//     type Row = Self;
-
-//     fn build(row: Self::Row) -> deserialize::Result<Self> {
-//         Ok(row)
-//     }
-// }
-
-// impl<T, DB> Selectable<DB> for Option<T>
-// where
-//     DB: Backend,
-//     T: Selectable<DB>,
-//     crate::dsl::Nullable<T::SelectExpression>: Expression,
-// {
-//     type SelectExpression = crate::dsl::Nullable<T::SelectExpression>;
-
-//     fn construct_selection() -> Self::SelectExpression {
-//         T::construct_selection().nullable()
-//     }
-// }
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql, Queryable, QueryableByName};
+use diesel::expression::{AsExpression, TypedExpressionType};
+use diesel::internal::derives::as_expression::Bound;
+use diesel::result::UnexpectedNullError;
+use diesel::row::NamedRow;
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::{is_nullable, Nullable, SingleValue, SqlType};
+use diesel::{Column, ExpressionMethods};
+
+use crate::defined::Defined;
+
+/// `Defined<T>` round-trips through any SQL type `T` supports, with
+/// `Defined::Undef` carrying the SQL `NULL` and `Defined::Def` carrying the
+/// value, same as `Option<T>` but distinguishable from "no row data at all".
+impl<T, ST, DB> FromSql<Nullable<ST>, DB> for Defined<T>
+where
+    T: FromSql<ST, DB>,
+    DB: Backend,
+    ST: SqlType<IsNull = is_nullable::NotNull>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        T::from_sql(bytes).map(Defined::Def)
+    }
+
+    fn from_nullable_sql(bytes: Option<DB::RawValue<'_>>) -> deserialize::Result<Self> {
+        match bytes {
+            Some(bytes) => T::from_sql(bytes).map(Defined::Def),
+            None => Ok(Defined::Undef),
+        }
+    }
+}
+
+impl<T, ST, DB> ToSql<Nullable<ST>, DB> for Defined<T>
+where
+    T: ToSql<ST, DB>,
+    DB: Backend,
+    ST: SqlType<IsNull = is_nullable::NotNull>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        match *self {
+            Defined::Def(ref value) => value.to_sql(out),
+            Defined::Undef => Ok(IsNull::Yes),
+        }
+    }
+}
+
+impl<T, ST> AsExpression<Nullable<ST>> for Defined<T>
+where
+    ST: SqlType<IsNull = is_nullable::NotNull>,
+    Nullable<ST>: TypedExpressionType,
+{
+    type Expression = Bound<Nullable<ST>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a, T, ST> AsExpression<Nullable<ST>> for &'a Defined<T>
+where
+    ST: SqlType<IsNull = is_nullable::NotNull>,
+    Nullable<ST>: TypedExpressionType,
+{
+    type Expression = Bound<Nullable<ST>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<ST, T, DB> Queryable<ST, DB> for Defined<T>
+where
+    ST: SingleValue<IsNull = is_nullable::IsNullable>,
+    DB: Backend,
+    Self: FromSql<ST, DB>,
+{
+    type Row = Self;
+
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+impl<T> Defined<T> {
+    /// Builds a single-column changeset fragment: `Def(v)` yields
+    /// `Some(column.eq(v))`, contributing `column = v` to the `UPDATE`,
+    /// while `Undef` yields `None`, leaving the column untouched.
+    ///
+    /// There's no `AsChangeset` impl for `Defined<T>` itself:
+    /// `#[derive(AsChangeset)]`'s codegen only special-cases the literal
+    /// `Option<T>` field type to get this "skip on none" behaviour, it
+    /// doesn't call a field type's own `AsChangeset::as_changeset()`, and
+    /// `AsChangeset` is implemented by whole changeset-producing structs
+    /// (or diesel's own `Eq<Column, Expr>`), not by plain SQL scalars like
+    /// `String`/`i32`. So a struct with `Defined<T>` fields builds its
+    /// changeset tuple from this helper per field instead of deriving one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use defined::Defined;
+    /// use diesel::ExpressionMethods;
+    ///
+    /// diesel::table! {
+    ///     users (id) {
+    ///         id -> Integer,
+    ///         name -> Text,
+    ///         age -> Integer,
+    ///     }
+    /// }
+    ///
+    /// let name: Defined<String> = Defined::Undef;
+    /// assert!(name.changeset(users::name).is_none());
+    ///
+    /// let name: Defined<String> = Defined::Def("Ada".to_string());
+    /// assert!(name.changeset(users::name).is_some());
+    ///
+    /// // apply only the supplied fields to an UPDATE:
+    /// let patch_name: Defined<String> = Defined::Def("Ada".to_string());
+    /// let patch_age: Defined<i32> = Defined::Undef;
+    /// let changes = (
+    ///     patch_name.changeset(users::name),
+    ///     patch_age.changeset(users::age),
+    /// );
+    /// let _query = diesel::update(users::table).set(changes);
+    /// ```
+    pub fn changeset<C>(self, column: C) -> Option<diesel::dsl::Eq<C, T>>
+    where
+        C: Column + ExpressionMethods,
+        C::SqlType: SqlType,
+        T: AsExpression<C::SqlType>,
+    {
+        match self {
+            Defined::Def(value) => Some(column.eq(value)),
+            Defined::Undef => None,
+        }
+    }
+}
+
+/// For `sql_query`/named-column result sets there's no static SQL type to
+/// hang a `Nullable<ST>` impl off of, so a NULL column instead surfaces as
+/// `T::build` failing with `UnexpectedNullError`; translate that into
+/// `Defined::Undef` and propagate every other error as-is.
+impl<T, DB> QueryableByName<DB> for Defined<T>
+where
+    DB: Backend,
+    T: QueryableByName<DB>,
+{
+    fn build<'a>(row: &impl NamedRow<'a, DB>) -> deserialize::Result<Self> {
+        match T::build(row) {
+            Ok(v) => Ok(Defined::Def(v)),
+            Err(e) if e.is::<UnexpectedNullError>() => Ok(Defined::Undef),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::defined::Defined;
+use crate::maybe::Maybe;
 use serde::{de::Error, de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::marker::PhantomData;
@@ -71,3 +72,90 @@ where
         }
     }
 }
+
+struct MaybeVisitor<T> {
+    marker: PhantomData<T>,
+}
+
+impl<'de, T> Visitor<'de> for MaybeVisitor<T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = Maybe<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("maybe")
+    }
+
+    #[inline]
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(Maybe::Null)
+    }
+
+    #[inline]
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Maybe::Value)
+    }
+}
+
+// The `Undefined` variant never comes from this impl: it's produced by
+// `Maybe`'s `Default` impl when paired with `#[serde(default)]` on the struct
+// field, which keeps an absent key from reaching `Deserialize::deserialize`
+// at all (the same trick `Option<Option<T>>` double-option fields use to tell
+// "absent" from "present and null" apart). Without `#[serde(default)]`, an
+// absent key goes through `deserialize_option` -> `visit_none` and comes out
+// as `Maybe::Null`, the same as an explicit `null`.
+impl<'de, T> Deserialize<'de> for Maybe<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(MaybeVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<T> Serialize for Maybe<T>
+where
+    T: Serialize,
+{
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Maybe::Value(ref value) => serializer.serialize_some(value),
+            Maybe::Null | Maybe::Undefined => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Helper for `#[serde(skip_serializing_if = "...")]` so callers can drop
+/// `Maybe::Undefined` keys from the output entirely instead of emitting them
+/// as `null`, matching RFC 7386 JSON Merge Patch semantics.
+///
+/// # Examples
+///
+/// ```
+/// use defined::Maybe;
+/// let x: Maybe<u32> = Maybe::Undefined;
+/// assert_eq!(defined::integrations::serde::is_undefined(&x), true);
+///
+/// let x: Maybe<u32> = Maybe::Null;
+/// assert_eq!(defined::integrations::serde::is_undefined(&x), false);
+/// ```
+#[inline]
+pub fn is_undefined<T>(value: &Maybe<T>) -> bool {
+    value.is_undefined()
+}
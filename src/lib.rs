@@ -3,6 +3,12 @@
 //! `Defined` is a alternative `Option` enum value with [`Defined::Undef`] value.
 //! [`Defined::Def`] is defined
 //! [`Defined::Undef`] is undefined
+//!
+//! [`Maybe`] is a tri-state sibling that additionally distinguishes an
+//! explicit JSON `null` ([`Maybe::Null`]) from a key that was never supplied
+//! ([`Maybe::Undefined`]), for RFC 7386 JSON Merge Patch semantics.
 mod defined;
 pub use defined::{Defined::{self, Def, Undef}};
+mod maybe;
+pub use maybe::Maybe;
 pub mod integrations;
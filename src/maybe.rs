@@ -0,0 +1,275 @@
+use std::panic;
+
+/// A Custom tri-state Option Enum distinguishing an explicit JSON `null`
+/// ([`Maybe::Null`]) from a key that was never supplied ([`Maybe::Undefined`]).
+/// Gives RFC 7386 JSON Merge Patch semantics on top of [`crate::Defined`].
+#[derive(Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+pub enum Maybe<T> {
+    Undefined,
+    Null,
+    Value(T),
+}
+
+impl<T> Clone for Maybe<T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        match self {
+            Maybe::Value(val) => Maybe::Value(val.clone()),
+            Maybe::Null => Maybe::Null,
+            Maybe::Undefined => Maybe::Undefined,
+        }
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        match (self, source) {
+            (Maybe::Value(to), Maybe::Value(from)) => to.clone_from(from),
+            (to, from) => *to = from.clone(),
+        }
+    }
+}
+
+impl<T> Default for Maybe<T> {
+    fn default() -> Self {
+        Self::Undefined
+    }
+}
+
+impl<T> From<T> for Maybe<T> {
+    fn from(val: T) -> Maybe<T> {
+        Maybe::Value(val)
+    }
+}
+
+impl<T> From<Option<T>> for Maybe<T> {
+    fn from(val: Option<T>) -> Maybe<T> {
+        match val {
+            Some(v) => Maybe::Value(v),
+            None => Maybe::Null,
+        }
+    }
+}
+
+// This is a similar function to reduce code size of .expect() and
+// produce panic message like std::Option .expect() function
+#[cfg_attr(not(feature = "panic_immediate_abort"), inline(never))]
+#[cfg_attr(feature = "panic_immediate_abort", inline)]
+#[cold]
+#[track_caller]
+const fn expect_failed(msg: &str) -> ! {
+    panic!("{}", msg)
+}
+
+impl<T> Maybe<T> {
+    /// Returns `true` if the maybe is a [`Maybe::Value`] value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use defined::Maybe;
+    /// let x: Maybe<u32> = Maybe::Value(1);
+    /// assert_eq!(x.is_value(), true);
+    ///
+    /// let x: Maybe<u32> = Maybe::Null;
+    /// assert_eq!(x.is_value(), false);
+    ///
+    /// let x: Maybe<u32> = Maybe::Undefined;
+    /// assert_eq!(x.is_value(), false);
+    /// ```
+    #[must_use = "if you intended to assert that this has a value, consider `.unwrap()` instead"]
+    #[inline]
+    pub const fn is_value(&self) -> bool {
+        matches!(*self, Maybe::Value(_))
+    }
+
+    /// Returns `true` if the maybe is a [`Maybe::Null`] value, i.e. the key
+    /// was present with an explicit JSON `null`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use defined::Maybe;
+    /// let x: Maybe<u32> = Maybe::Null;
+    /// assert_eq!(x.is_null(), true);
+    ///
+    /// let x: Maybe<u32> = Maybe::Undefined;
+    /// assert_eq!(x.is_null(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_null(&self) -> bool {
+        matches!(*self, Maybe::Null)
+    }
+
+    /// Returns `true` if the maybe is [`Maybe::Undefined`], i.e. the key was
+    /// never supplied at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use defined::Maybe;
+    /// let x: Maybe<u32> = Maybe::Undefined;
+    /// assert_eq!(x.is_undefined(), true);
+    ///
+    /// let x: Maybe<u32> = Maybe::Null;
+    /// assert_eq!(x.is_undefined(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_undefined(&self) -> bool {
+        matches!(*self, Maybe::Undefined)
+    }
+
+    #[inline]
+    pub const fn as_ref(&self) -> Maybe<&T> {
+        match *self {
+            Maybe::Value(ref val) => Maybe::Value(val),
+            Maybe::Null => Maybe::Null,
+            Maybe::Undefined => Maybe::Undefined,
+        }
+    }
+
+    #[inline]
+    pub fn as_mut(&mut self) -> Maybe<&mut T> {
+        match *self {
+            Maybe::Value(ref mut val) => Maybe::Value(val),
+            Maybe::Null => Maybe::Null,
+            Maybe::Undefined => Maybe::Undefined,
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn expect(self, msg: &str) -> T {
+        match self {
+            Maybe::Value(val) => val,
+            _ => expect_failed(msg),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn unwrap(self) -> T {
+        match self {
+            Maybe::Value(val) => val,
+            _ => panic!("called Maybe::unwrap() on a value without `Maybe::Value`"),
+        }
+    }
+
+    #[inline]
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Maybe::Value(val) => val,
+            _ => default,
+        }
+    }
+
+    #[inline]
+    pub fn unwrap_or_else<F>(self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        match self {
+            Maybe::Value(val) => val,
+            _ => f(),
+        }
+    }
+
+    #[inline]
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        match self {
+            Maybe::Value(x) => x,
+            _ => T::default(),
+        }
+    }
+
+    #[inline]
+    pub fn map<U, F>(self, f: F) -> Maybe<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Maybe::Value(val) => Maybe::Value(f(val)),
+            Maybe::Null => Maybe::Null,
+            Maybe::Undefined => Maybe::Undefined,
+        }
+    }
+
+    #[inline]
+    pub fn inspect<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T),
+    {
+        if let Maybe::Value(ref val) = self {
+            f(val);
+        }
+        self
+    }
+
+    #[inline]
+    pub fn map_or<U, F>(self, default: U, f: F) -> U
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Maybe::Value(val) => f(val),
+            _ => default,
+        }
+    }
+
+    #[inline]
+    pub fn map_or_else<U, D, F>(self, default: D, f: F) -> U
+    where
+        D: FnOnce() -> U,
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Maybe::Value(val) => f(val),
+            _ => default(),
+        }
+    }
+
+    #[inline]
+    pub fn ok_or<E>(self, err: E) -> Result<T, E> {
+        match self {
+            Maybe::Value(val) => Ok(val),
+            _ => Err(err),
+        }
+    }
+
+    #[inline]
+    pub fn ok_or_else<E, F>(self, err: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E,
+    {
+        match self {
+            Maybe::Value(val) => Ok(val),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl<T> From<Maybe<T>> for Option<Option<T>> {
+    fn from(value: Maybe<T>) -> Self {
+        match value {
+            Maybe::Value(val) => Some(Some(val)),
+            Maybe::Null => Some(None),
+            Maybe::Undefined => Option::<Option<T>>::None,
+        }
+    }
+}
+
+impl<T> From<Maybe<T>> for Option<T> {
+    fn from(value: Maybe<T>) -> Self {
+        match value {
+            Maybe::Value(val) => Some(val),
+            _ => Option::None,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "sqlite")]
+
+use defined::Defined;
+use diesel::prelude::*;
+
+diesel::table! {
+    users (id) {
+        id -> Integer,
+        name -> Text,
+        age -> Integer,
+    }
+}
+
+fn connection() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, age INTEGER NOT NULL)",
+    )
+    .execute(&mut conn)
+    .unwrap();
+    conn
+}
+
+#[test]
+fn undef_fields_are_skipped_from_the_update() {
+    let mut conn = connection();
+    diesel::insert_into(users::table)
+        .values((users::id.eq(1), users::name.eq("Ada"), users::age.eq(30)))
+        .execute(&mut conn)
+        .unwrap();
+
+    let patch_name: Defined<String> = Defined::Def("Grace".to_string());
+    let patch_age: Defined<i32> = Defined::Undef;
+    let changes = (
+        patch_name.changeset(users::name),
+        patch_age.changeset(users::age),
+    );
+
+    diesel::update(users::table.find(1))
+        .set(changes)
+        .execute(&mut conn)
+        .unwrap();
+
+    let (name, age) = users::table
+        .select((users::name, users::age))
+        .filter(users::id.eq(1))
+        .first::<(String, i32)>(&mut conn)
+        .unwrap();
+
+    assert_eq!(name, "Grace");
+    assert_eq!(age, 30);
+}